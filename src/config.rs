@@ -1,6 +1,8 @@
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use std::path::PathBuf;
 
+use crate::schemas::{LoadedSchemaOptions, SchemaOptions, SchemaSource};
+
 #[derive(Parser, Debug)]
 #[command(
     name = "action-validator",
@@ -12,9 +14,54 @@ pub struct CliConfig {
     #[arg(short, long)]
     pub verbose: bool,
 
-    /// Input file
-    #[arg(name = "path_to_action_yaml")]
-    pub src: PathBuf,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Human)]
+    pub format: OutputFormat,
+
+    /// Watch the input paths and re-validate whenever a file changes
+    #[arg(short, long)]
+    pub watch: bool,
+
+    /// Path or URL to a schema to use instead of the bundled SchemaStore
+    /// schema
+    #[arg(long)]
+    pub schema: Option<String>,
+
+    /// Additional schema document (path or URL) to make available for
+    /// `$ref` resolution. May be passed multiple times.
+    #[arg(long = "additional-schema")]
+    pub additional_schemas: Vec<String>,
+
+    /// Input file(s) or director(y/ies). Directories are searched
+    /// recursively for `action.yml`/`action.yaml` files and for
+    /// `*.yml`/`*.yaml` files under their `.github/workflows`; other YAML
+    /// files are not picked up.
+    #[arg(name = "path_to_action_yaml", required = true)]
+    pub src: Vec<PathBuf>,
+}
+
+impl CliConfig {
+    pub fn schema_options(&self) -> SchemaOptions {
+        SchemaOptions {
+            schema_override: self.schema.as_deref().map(SchemaSource::parse),
+            additional_schemas: self
+                .additional_schemas
+                .iter()
+                .map(|s| SchemaSource::parse(s))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable output (the default)
+    #[default]
+    Human,
+    /// A JSON array of `ValidationState`s, one per validated file
+    Json,
+    /// A SARIF 2.1.0 run, suitable for uploading to GitHub code scanning
+    Sarif,
 }
 
 #[derive(Copy, Clone)]
@@ -34,4 +81,5 @@ pub struct Config<'a> {
     pub action_type: ActionType,
     pub src: &'a str,
     pub verbose: bool,
+    pub schemas: &'a LoadedSchemaOptions,
 }
@@ -0,0 +1,274 @@
+use serde_json::Value;
+
+use crate::validation_error::{ValidationError, ValidationErrorMetadata};
+use crate::validation_state::ValidationState;
+
+type FormatChecker = fn(&Value, &mut ValidationState);
+
+/// Format checkers registered by document location, run after schema
+/// validation to catch GitHub semantics a JSON schema can't express.
+const FORMAT_CHECKERS: &[FormatChecker] =
+    &[check_cron_schedules, check_runner_labels, check_expressions];
+
+pub fn validate_formats(doc: &Value, state: &mut ValidationState) {
+    for checker in FORMAT_CHECKERS {
+        checker(doc, state);
+    }
+}
+
+fn check_cron_schedules(doc: &Value, state: &mut ValidationState) {
+    let Some(schedules) = doc["on"]["schedule"].as_array() else {
+        return;
+    };
+
+    for (i, schedule) in schedules.iter().enumerate() {
+        let Some(cron) = schedule["cron"].as_str() else {
+            continue;
+        };
+
+        if let Err(detail) = validate_cron(cron) {
+            state.errors.push(ValidationError::InvalidCronError {
+                meta: ValidationErrorMetadata {
+                    code: "invalid_cron".into(),
+                    path: format!("/on/schedule/{i}/cron"),
+                    title: "Invalid cron expression".into(),
+                    detail: Some(detail),
+                    location: None,
+                },
+            });
+        }
+    }
+}
+
+fn validate_cron(cron: &str) -> Result<(), String> {
+    const FIELD_RANGES: [(u32, u32); 5] = [(0, 59), (0, 23), (1, 31), (1, 12), (0, 6)];
+
+    let fields: Vec<&str> = cron.split_whitespace().collect();
+    if fields.len() != 5 {
+        return Err(format!(
+            "cron expression `{cron}` must have 5 fields (minute hour day month weekday), found {}",
+            fields.len()
+        ));
+    }
+
+    for (field, &(min, max)) in fields.iter().zip(FIELD_RANGES.iter()) {
+        validate_cron_field(field, min, max)?;
+    }
+
+    Ok(())
+}
+
+fn validate_cron_field(field: &str, min: u32, max: u32) -> Result<(), String> {
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, Some(step)),
+            None => (part, None),
+        };
+
+        if let Some(step) = step {
+            if step.parse::<u32>().map_or(true, |step| step == 0) {
+                return Err(format!("invalid step `{step}` in cron field `{field}`"));
+            }
+        }
+
+        if range == "*" {
+            continue;
+        }
+
+        let (start, end) = match range.split_once('-') {
+            Some((start, end)) => (start, end),
+            None => (range, range),
+        };
+
+        let start: u32 = start
+            .parse()
+            .map_err(|_| format!("invalid value `{start}` in cron field `{field}`"))?;
+        let end: u32 = end
+            .parse()
+            .map_err(|_| format!("invalid value `{end}` in cron field `{field}`"))?;
+
+        if start < min || end > max || start > end {
+            return Err(format!(
+                "value `{range}` in cron field `{field}` is out of range {min}-{max}"
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn check_runner_labels(doc: &Value, state: &mut ValidationState) {
+    let Some(jobs) = doc["jobs"].as_object() else {
+        return;
+    };
+
+    for (job_name, job) in jobs.iter() {
+        let path = format!("/jobs/{job_name}/runs-on");
+
+        match &job["runs-on"] {
+            Value::String(label) => check_runner_label(label, &path, state),
+            Value::Array(labels) => {
+                for (i, label) in labels.iter().enumerate() {
+                    if let Some(label) = label.as_str() {
+                        check_runner_label(label, &format!("{path}/{i}"), state);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn check_runner_label(label: &str, path: &str, state: &mut ValidationState) {
+    // Labels resolved at runtime (e.g. `${{ matrix.os }}`) can't be checked
+    // statically.
+    if label.contains("${{") {
+        return;
+    }
+
+    if is_obviously_garbage_label(label) {
+        state.errors.push(ValidationError::InvalidRunnerLabelError {
+            meta: ValidationErrorMetadata {
+                code: "invalid_runner_label".into(),
+                path: path.into(),
+                title: "Invalid runs-on label".into(),
+                detail: Some(format!("`{label}` does not look like a valid runner label")),
+                location: None,
+            },
+        });
+    }
+}
+
+/// GitHub ships new hosted runner images regularly and self-hosted labels
+/// are arbitrary org-chosen strings, so we can't maintain an exhaustive
+/// allow-list of "valid" labels. Only flag labels that couldn't possibly be
+/// a real label, rather than guessing at what GitHub might call next year's
+/// image.
+fn is_obviously_garbage_label(label: &str) -> bool {
+    label.trim().is_empty() || label != label.trim()
+}
+
+fn check_expressions(doc: &Value, state: &mut ValidationState) {
+    walk_expressions(doc, "", state);
+}
+
+fn walk_expressions(value: &Value, path: &str, state: &mut ValidationState) {
+    match value {
+        Value::String(s) => {
+            if let Err(detail) = validate_expression_syntax(s) {
+                state.errors.push(ValidationError::InvalidExpressionError {
+                    meta: ValidationErrorMetadata {
+                        code: "invalid_expression".into(),
+                        path: path.into(),
+                        title: "Invalid expression syntax".into(),
+                        detail: Some(detail),
+                        location: None,
+                    },
+                });
+            }
+        }
+        Value::Array(items) => {
+            for (i, item) in items.iter().enumerate() {
+                walk_expressions(item, &format!("{path}/{i}"), state);
+            }
+        }
+        Value::Object(map) => {
+            for (key, item) in map.iter() {
+                walk_expressions(item, &format!("{path}/{key}"), state);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn validate_expression_syntax(value: &str) -> Result<(), String> {
+    let mut rest = value;
+
+    while let Some(start) = rest.find("${{") {
+        if rest[..start].contains("}}") {
+            return Err(format!("unmatched `}}}}` in: {value}"));
+        }
+
+        let body_start = start + "${{".len();
+
+        let Some(end) = rest[body_start..].find("}}") else {
+            return Err(format!("unterminated `${{{{` in: {value}"));
+        };
+
+        let body = &rest[body_start..body_start + end];
+        if body.contains("${{") {
+            return Err(format!("nested `${{{{` before a matching `}}}}` in: {value}"));
+        }
+
+        if body.trim().is_empty() {
+            return Err(format!("empty `${{{{ }}}}` expression in: {value}"));
+        }
+
+        rest = &rest[body_start + end + "}}".len()..];
+    }
+
+    if rest.contains("}}") {
+        return Err(format!("unmatched `}}}}` in: {value}"));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_valid_cron() {
+        assert!(validate_cron("0 0 * * *").is_ok());
+        assert!(validate_cron("*/15 0-6,12 1-15 */2 1-5").is_ok());
+    }
+
+    #[test]
+    fn rejects_cron_with_wrong_field_count() {
+        assert!(validate_cron("0 0 * *").is_err());
+    }
+
+    #[test]
+    fn rejects_cron_field_out_of_range() {
+        assert!(validate_cron("0 24 * * *").is_err());
+    }
+
+    #[test]
+    fn rejects_cron_zero_step() {
+        assert!(validate_cron("*/0 * * * *").is_err());
+    }
+
+    #[test]
+    fn accepts_balanced_expressions() {
+        assert!(validate_expression_syntax("${{ matrix.os }}").is_ok());
+        assert!(
+            validate_expression_syntax("${{ matrix.os }} and ${{ matrix.version }}").is_ok()
+        );
+    }
+
+    #[test]
+    fn rejects_unterminated_expression() {
+        assert!(validate_expression_syntax("${{ matrix.os").is_err());
+    }
+
+    #[test]
+    fn rejects_nested_expression() {
+        assert!(validate_expression_syntax("${{ ${{ matrix.os }} }}").is_err());
+    }
+
+    #[test]
+    fn rejects_empty_expression() {
+        assert!(validate_expression_syntax("${{ }}").is_err());
+    }
+
+    #[test]
+    fn rejects_stray_close_delimiter_after_a_match() {
+        assert!(validate_expression_syntax("${{ matrix.os }} }}").is_err());
+    }
+
+    #[test]
+    fn rejects_stray_close_delimiter_before_a_match() {
+        assert!(validate_expression_syntax("}} ${{ matrix.os }}").is_err());
+    }
+}
@@ -1,21 +1,27 @@
 mod config;
+mod format_checks;
 mod log;
+mod sarif;
 mod schemas;
 mod utils;
 mod validation_error;
 mod validation_state;
+mod yaml_location;
 
-use config::{ActionType, JsConfig, RunConfig};
+use config::{ActionType, JsConfig, OutputFormat, RunConfig};
 use std::fs;
+use std::path::{Path, PathBuf};
 use utils::set_panic_hook;
 use validation_error::{ValidationError, ValidationErrorMetadata};
 use validation_state::ValidationState;
 use wasm_bindgen::prelude::*;
 
 pub use crate::config::CliConfig;
-use crate::schemas::{validate_as_action, validate_as_workflow};
+use crate::schemas::{validate_as_action, validate_as_workflow, LoadedSchemaOptions};
 use glob::glob;
+use sarif::SarifLog;
 use serde_json::{Map, Value};
+use yaml_location::LocationIndex;
 
 // When the `wee_alloc` feature is enabled, use `wee_alloc` as the global
 // allocator.
@@ -56,33 +62,348 @@ pub fn run_js(config: &JsConfig) -> JsValue {
 }
 
 pub fn run_cli(config: &CliConfig) -> Result<(), Box<dyn std::error::Error>> {
-    let file_name = config
-        .src
-        .file_name()
-        .ok_or("Unable to derive file name from src!")?
-        .to_str();
-
-    let run_config = RunConfig {
-        file_path: config.src.to_str(),
-        file_name,
-        action_type: match file_name {
-            Some("action.yml") | Some("action.yaml") => ActionType::Action,
-            _ => ActionType::Workflow,
-        },
-        src: &fs::read_to_string(&config.src)?,
-        verbose: config.verbose,
-    };
+    let mut files = Vec::new();
+    for path in &config.src {
+        files.extend(collect_candidate_files(path)?);
+    }
 
-    let state = run(&run_config);
+    // Read and parse `--schema`/`--additional-schema` sources once up front,
+    // rather than re-reading them for every file (and, under `--watch`,
+    // every re-validation cycle). A failure here (bad path, unreachable URL,
+    // malformed JSON) is reported the same way a per-file schema failure
+    // would be: as a `SchemaLoadError` printed through `config.format`,
+    // rather than a raw error bypassing `--format json`/`--format sarif`.
+    let schemas = match config.schema_options().load() {
+        Ok(schemas) => schemas,
+        Err(e) => {
+            print_states(config, &[schema_validation_state(Err(e))])?;
+            return Err("validation failed".into());
+        }
+    };
 
-    if !state.is_valid() {
-        log::error(&format!("Validation failed: {state:#?}"));
+    #[cfg(not(feature = "js"))]
+    if config.watch {
+        return watch_cli(config, &files, &schemas);
     }
 
-    if state.is_valid() {
-        Ok(())
-    } else {
+    validate_files(config, &files, &schemas)
+}
+
+/// Validates `files` once and prints the results in `config.format`,
+/// returning `Err` if any file failed validation.
+fn validate_files(
+    config: &CliConfig,
+    files: &[PathBuf],
+    schemas: &LoadedSchemaOptions,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let states: Vec<ValidationState> = files
+        .iter()
+        .map(|path| {
+            let file_name = path.file_name().and_then(|f| f.to_str());
+            let src = fs::read_to_string(path)?;
+            let run_config = RunConfig {
+                file_path: path.to_str(),
+                file_name,
+                action_type: action_type_for_path(path),
+                src: &src,
+                verbose: config.verbose,
+                schemas,
+            };
+
+            Ok(run(&run_config))
+        })
+        .collect::<Result<_, Box<dyn std::error::Error>>>()?;
+
+    let any_invalid = states.iter().any(|state| !state.is_valid());
+
+    print_states(config, &states)?;
+
+    if any_invalid {
         Err("validation failed".into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Prints `states` in `config.format`. Shared by a single validation run and
+/// the `SchemaLoadError` path taken when `--schema`/`--additional-schema`
+/// can't be loaded, so both report through the same, user-selected format.
+fn print_states(
+    config: &CliConfig,
+    states: &[ValidationState],
+) -> Result<(), Box<dyn std::error::Error>> {
+    match config.format {
+        OutputFormat::Human => {
+            for state in states {
+                if !state.is_valid() {
+                    log::error(&format!("Validation failed: {state:#?}"));
+                }
+            }
+        }
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(states)?),
+        OutputFormat::Sarif => {
+            println!("{}", serde_json::to_string_pretty(&SarifLog::from(states))?)
+        }
+    }
+
+    Ok(())
+}
+
+/// Watches `files` for changes and re-validates on every change, clearing
+/// the screen and printing fresh results each cycle. Rapid bursts of events
+/// (e.g. an editor writing a file in several steps) are coalesced into a
+/// single re-validation; a file whose read fails transiently (file
+/// mid-save) is left out of that cycle's results rather than reported as an
+/// error, while the other files are still validated and printed. Runs until
+/// the process is interrupted (Ctrl-C ends it through the default signal
+/// handling); returns `Ok` only if the watcher's channel disconnects.
+///
+/// Watches each file's parent directory rather than the file itself: most
+/// editors (vim, VS Code, ...) save by writing a temp file and renaming it
+/// over the original, which replaces the inode a direct file watch tracks
+/// and silently stops delivering events after the first edit.
+#[cfg(not(feature = "js"))]
+fn watch_cli(
+    config: &CliConfig,
+    files: &[PathBuf],
+    schemas: &LoadedSchemaOptions,
+) -> Result<(), Box<dyn std::error::Error>> {
+    use notify::{RecursiveMode, Watcher};
+    use std::collections::HashSet;
+    use std::sync::mpsc::channel;
+    use std::time::Duration;
+
+    const DEBOUNCE: Duration = Duration::from_millis(200);
+
+    let (tx, rx) = channel();
+    let mut watcher = notify::recommended_watcher(tx)?;
+
+    let mut watched_dirs = HashSet::new();
+    for file in files {
+        // A bare relative filename like `action.yml` has no directory
+        // component, so `parent()` returns `Some("")` rather than `None` —
+        // watch `.` in that case instead of handing `notify` an empty path.
+        let dir = file
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+            .unwrap_or_else(|| Path::new("."));
+
+        if watched_dirs.insert(dir) {
+            watcher.watch(dir, RecursiveMode::NonRecursive)?;
+        }
+    }
+
+    // `notify` reports event paths joined onto the absolute form of each
+    // watched directory, so compare against the files' absolute paths too.
+    let cwd = std::env::current_dir()?;
+    let watched_files: HashSet<PathBuf> = files.iter().map(|file| cwd.join(file)).collect();
+
+    print_validation_cycle(config, files, schemas);
+
+    loop {
+        let event = match rx.recv() {
+            Ok(Ok(event)) => event,
+            Ok(Err(_)) => continue, // transient watcher error; skip this cycle
+            Err(_) => return Ok(()), // the watcher stopped sending events
+        };
+
+        if !event.paths.iter().any(|path| watched_files.contains(path)) {
+            continue;
+        }
+
+        // Drain any further events from the same burst of changes.
+        while rx.recv_timeout(DEBOUNCE).is_ok() {}
+
+        print!("\x1B[2J\x1B[1;1H");
+        print_validation_cycle(config, files, schemas);
+    }
+}
+
+/// Validates `files` for a single watch cycle and prints the results in
+/// `config.format`. Files that fail to read (e.g. mid-save) are silently
+/// skipped for this cycle rather than aborting it.
+#[cfg(not(feature = "js"))]
+fn print_validation_cycle(config: &CliConfig, files: &[PathBuf], schemas: &LoadedSchemaOptions) {
+    let states: Vec<ValidationState> = files
+        .iter()
+        .filter_map(|path| {
+            let file_name = path.file_name().and_then(|f| f.to_str());
+            let src = fs::read_to_string(path).ok()?;
+            let run_config = RunConfig {
+                file_path: path.to_str(),
+                file_name,
+                action_type: action_type_for_path(path),
+                src: &src,
+                verbose: config.verbose,
+                schemas,
+            };
+
+            Some(run(&run_config))
+        })
+        .collect();
+
+    match config.format {
+        OutputFormat::Human => {
+            for state in &states {
+                if !state.is_valid() {
+                    log::error(&format!("Validation failed: {state:#?}"));
+                }
+            }
+        }
+        OutputFormat::Json => {
+            if let Ok(json) = serde_json::to_string_pretty(&states) {
+                println!("{json}");
+            }
+        }
+        OutputFormat::Sarif => {
+            if let Ok(json) = serde_json::to_string_pretty(&SarifLog::from(states.as_slice())) {
+                println!("{json}");
+            }
+        }
+    }
+}
+
+/// Determines whether `path` should be validated as an Action or a Workflow
+/// definition, preferring the `.github/workflows` convention and falling
+/// back to the `action.yml`/`action.yaml` file name.
+fn action_type_for_path(path: &Path) -> ActionType {
+    let in_workflows_dir = path
+        .components()
+        .map(|component| component.as_os_str())
+        .collect::<Vec<_>>()
+        .windows(2)
+        .any(|window| window[0] == ".github" && window[1] == "workflows");
+
+    match path.file_name().and_then(|f| f.to_str()) {
+        Some("action.yml") | Some("action.yaml") if !in_workflows_dir => ActionType::Action,
+        _ => ActionType::Workflow,
+    }
+}
+
+/// Resolves `path` to the list of candidate YAML files to validate: `path`
+/// itself if it's a file, or every `.github/workflows/**/*.yml(.yaml)` and
+/// `action.yml`/`action.yaml` found recursively beneath it if it's a
+/// directory. Directories hold plenty of other YAML (docker-compose,
+/// mkdocs, helm values, ...) that isn't a GitHub Action or Workflow, so we
+/// only pick up files in locations GitHub itself recognizes.
+fn collect_candidate_files(
+    path: &Path,
+) -> Result<Vec<PathBuf>, Box<dyn std::error::Error>> {
+    if !path.is_dir() {
+        return Ok(vec![path.to_path_buf()]);
+    }
+
+    let mut files = Vec::new();
+    for extension in ["yml", "yaml"] {
+        let patterns = [
+            path.join(".github")
+                .join("workflows")
+                .join("**")
+                .join(format!("*.{extension}")),
+            path.join("**").join(format!("action.{extension}")),
+        ];
+
+        for pattern in patterns {
+            let pattern = pattern.to_str().ok_or("Invalid path")?;
+
+            for entry in glob(pattern)? {
+                files.push(entry?);
+            }
+        }
+    }
+
+    files.sort();
+    files.dedup();
+
+    Ok(files)
+}
+
+#[cfg(test)]
+mod collect_candidate_files_tests {
+    use super::*;
+    use std::fs;
+
+    /// A directory under `std::env::temp_dir()` that's removed when dropped,
+    /// so each test gets an isolated scratch directory without pulling in a
+    /// `tempfile` dependency.
+    struct ScratchDir(PathBuf);
+
+    impl ScratchDir {
+        fn new(name: &str) -> Self {
+            let dir = std::env::temp_dir().join(format!("action-validator-test-{name}"));
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(&dir).unwrap();
+            ScratchDir(dir)
+        }
+    }
+
+    impl Drop for ScratchDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn returns_the_path_itself_when_not_a_directory() {
+        let scratch = ScratchDir::new("single-file");
+        let file = scratch.0.join("workflow.yml");
+        fs::write(&file, "on: push\n").unwrap();
+
+        let files = collect_candidate_files(&file).unwrap();
+
+        assert_eq!(files, vec![file]);
+    }
+
+    #[test]
+    fn dedups_a_workflow_action_file_matched_by_both_glob_patterns() {
+        let scratch = ScratchDir::new("dedup");
+        let workflows_dir = scratch.0.join(".github").join("workflows");
+        fs::create_dir_all(&workflows_dir).unwrap();
+
+        // `action.yml` inside `.github/workflows` matches both the
+        // `.github/workflows/**/*.yml` pattern and the `**/action.yml`
+        // pattern, and should only be reported once.
+        let action_file = workflows_dir.join("action.yml");
+        fs::write(&action_file, "runs:\n  using: node20\n").unwrap();
+
+        let files = collect_candidate_files(&scratch.0).unwrap();
+
+        assert_eq!(files, vec![action_file]);
+    }
+
+    #[test]
+    fn ignores_yaml_outside_recognized_locations() {
+        let scratch = ScratchDir::new("irrelevant");
+        fs::write(scratch.0.join("docker-compose.yml"), "services: {}\n").unwrap();
+
+        let files = collect_candidate_files(&scratch.0).unwrap();
+
+        assert!(files.is_empty());
+    }
+}
+
+/// Converts a schema-compilation/validation failure (e.g. an unreadable
+/// `--schema` path, an unreachable URL, or a malformed schema document) into
+/// a `ValidationState` carrying a single `SchemaLoadError`, instead of
+/// letting it propagate as a generic error.
+fn schema_validation_state(
+    result: Result<ValidationState, Box<dyn std::error::Error>>,
+) -> ValidationState {
+    match result {
+        Ok(state) => state,
+        Err(e) => ValidationState {
+            action_type: None,
+            file_path: None,
+            errors: vec![ValidationError::SchemaLoadError {
+                meta: ValidationErrorMetadata {
+                    code: "schema_load_error".into(),
+                    path: "".into(),
+                    title: "Unable to load or compile schema".into(),
+                    detail: Some(e.to_string()),
+                    location: None,
+                },
+            }],
+        },
     }
 }
 
@@ -96,26 +417,41 @@ fn run(config: &RunConfig) -> ValidationState {
             file_path: Some(file_name.to_string()),
             errors: vec![err.into()],
         },
-        Ok(doc) => match config.action_type {
-            ActionType::Action => {
-                if config.verbose {
-                    log::log(&format!("Treating {} as an Action definition", file_name));
-                }
-                validate_as_action(&doc)
-            }
-            ActionType::Workflow => {
-                if config.verbose {
-                    log::log(&format!("Treating {} as a Workflow definition", file_name));
+        Ok(doc) => {
+            let mut state = match config.action_type {
+                ActionType::Action => {
+                    if config.verbose {
+                        log::log(&format!("Treating {} as an Action definition", file_name));
+                    }
+                    schema_validation_state(validate_as_action(&doc, config.schemas))
                 }
-                // TODO: Re-enable path and job validation
-                let mut state = validate_as_workflow(&doc);
+                ActionType::Workflow => {
+                    if config.verbose {
+                        log::log(&format!("Treating {} as a Workflow definition", file_name));
+                    }
+                    // TODO: Re-enable path and job validation
+                    let mut state =
+                        schema_validation_state(validate_as_workflow(&doc, config.schemas));
 
-                validate_paths(&doc, &mut state);
-                validate_job_needs(&doc, &mut state);
+                    validate_paths(&doc, &mut state);
+                    validate_job_needs(&doc, &mut state);
+                    format_checks::validate_formats(&doc, &mut state);
 
-                state
+                    state
+                }
+            };
+
+            let locations = LocationIndex::build(config.src);
+            for err in state.errors.iter_mut() {
+                if let Some(meta) = err.metadata_mut() {
+                    if meta.location.is_none() {
+                        meta.location = locations.resolve(&meta.path);
+                    }
+                }
             }
-        },
+
+            state
+        }
     };
 
     state.action_type = Some(config.action_type);
@@ -173,6 +509,7 @@ fn validate_globs(globs: &serde_json::Value, path: &str, state: &mut ValidationS
                                     detail: Some(format!(
                                         "Glob {g} in {path} does not match any files"
                                     )),
+                                    location: None,
                                 },
                             });
                     }
@@ -184,6 +521,7 @@ fn validate_globs(globs: &serde_json::Value, path: &str, state: &mut ValidationS
                             path: path.into(),
                             title: "Glob does not match any files".into(),
                             detail: Some(format!("Glob {g} in {path} is invalid: {e}")),
+                            location: None,
                         },
                     });
                 }
@@ -208,6 +546,7 @@ fn validate_job_needs(doc: &serde_json::Value, state: &mut ValidationState) {
                 path: format!("/jobs/{job_name}/needs"),
                 title: "Unresolved job".into(),
                 detail: Some(format!("unresolved job {needs_str}")),
+                location: None,
             },
         });
     }
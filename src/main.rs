@@ -6,11 +6,13 @@ fn main() {
     let config = CliConfig::parse();
 
     if let Err(e) = action_validator::run_cli(&config) {
-        println!(
-            "Fatal error validating {}: {}",
-            config.src.to_str().unwrap(),
-            e
-        );
+        let paths = config
+            .src
+            .iter()
+            .map(|path| path.to_str().unwrap())
+            .collect::<Vec<_>>()
+            .join(", ");
+        println!("Fatal error validating {paths}: {e}");
         process::exit(1);
     }
 }
@@ -0,0 +1,128 @@
+use serde::Serialize;
+
+use crate::validation_state::ValidationState;
+
+const SCHEMA_URI: &str =
+    "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/master/Schemata/sarif-schema-2.1.0.json";
+
+#[derive(Serialize, Debug)]
+pub struct SarifLog {
+    #[serde(rename = "$schema")]
+    pub schema: &'static str,
+    pub version: &'static str,
+    pub runs: Vec<SarifRun>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct SarifRun {
+    pub tool: SarifTool,
+    pub results: Vec<SarifResult>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct SarifTool {
+    pub driver: SarifDriver,
+}
+
+#[derive(Serialize, Debug)]
+pub struct SarifDriver {
+    pub name: &'static str,
+    pub version: &'static str,
+}
+
+#[derive(Serialize, Debug)]
+pub struct SarifResult {
+    #[serde(rename = "ruleId")]
+    pub rule_id: String,
+    pub level: &'static str,
+    pub message: SarifMessage,
+    pub locations: Vec<SarifLocation>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct SarifMessage {
+    pub text: String,
+}
+
+#[derive(Serialize, Debug)]
+pub struct SarifLocation {
+    #[serde(rename = "physicalLocation")]
+    pub physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Serialize, Debug)]
+pub struct SarifPhysicalLocation {
+    #[serde(rename = "artifactLocation")]
+    pub artifact_location: SarifArtifactLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<SarifRegion>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct SarifArtifactLocation {
+    pub uri: String,
+}
+
+#[derive(Serialize, Debug)]
+pub struct SarifRegion {
+    #[serde(rename = "startLine")]
+    pub start_line: usize,
+    #[serde(rename = "startColumn")]
+    pub start_column: usize,
+}
+
+impl From<&[ValidationState]> for SarifLog {
+    fn from(states: &[ValidationState]) -> Self {
+        let results = states
+            .iter()
+            .flat_map(|state| {
+                let file_path = state.file_path.clone().unwrap_or_default();
+                state
+                    .errors
+                    .iter()
+                    .map(move |err| sarif_result(err, &file_path))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        SarifLog {
+            schema: SCHEMA_URI,
+            version: "2.1.0",
+            runs: vec![SarifRun {
+                tool: SarifTool {
+                    driver: SarifDriver {
+                        name: "action-validator",
+                        version: env!("CARGO_PKG_VERSION"),
+                    },
+                },
+                results,
+            }],
+        }
+    }
+}
+
+fn sarif_result(err: &crate::validation_error::ValidationError, file_path: &str) -> SarifResult {
+    let summary = err.summary();
+
+    SarifResult {
+        rule_id: summary.code.to_string(),
+        level: "error",
+        message: SarifMessage {
+            text: match summary.detail {
+                Some(detail) => format!("{}: {detail}", summary.title),
+                None => summary.title.to_string(),
+            },
+        },
+        locations: vec![SarifLocation {
+            physical_location: SarifPhysicalLocation {
+                artifact_location: SarifArtifactLocation {
+                    uri: file_path.to_string(),
+                },
+                region: summary.location.map(|location| SarifRegion {
+                    start_line: location.line,
+                    start_column: location.column,
+                }),
+            },
+        }],
+    }
+}
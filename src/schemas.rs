@@ -1,28 +1,128 @@
+use std::fs;
+use std::path::PathBuf;
+
 use serde_json::Value;
-use valico::json_schema::ValidationState;
 
 use crate::log::error;
+use crate::validation_state::ValidationState;
+
+/// A schema document to load, either from disk or by fetching a URL.
+#[derive(Debug, Clone)]
+pub enum SchemaSource {
+    Path(PathBuf),
+    Url(String),
+}
+
+impl SchemaSource {
+    pub fn parse(value: &str) -> Self {
+        if value.starts_with("http://") || value.starts_with("https://") {
+            SchemaSource::Url(value.to_string())
+        } else {
+            SchemaSource::Path(PathBuf::from(value))
+        }
+    }
+
+    fn load(&self) -> Result<String, Box<dyn std::error::Error>> {
+        match self {
+            SchemaSource::Path(path) => Ok(fs::read_to_string(path)?),
+            SchemaSource::Url(url) => Ok(ureq::get(url).call()?.into_string()?),
+        }
+    }
+}
+
+/// Compilation options threaded through schema validation, letting callers
+/// supersede the bundled SchemaStore schema and register extra schemas for
+/// `$ref` resolution (e.g. an organization's custom action schema).
+#[derive(Debug, Clone, Default)]
+pub struct SchemaOptions {
+    pub schema_override: Option<SchemaSource>,
+    pub additional_schemas: Vec<SchemaSource>,
+}
+
+impl SchemaOptions {
+    /// Reads and parses every configured schema source exactly once. Callers
+    /// validating many files (or re-validating on every `--watch` cycle)
+    /// should call this a single time up front and reuse the result, rather
+    /// than re-reading disk or re-fetching a `--schema` URL per file.
+    pub fn load(&self) -> Result<LoadedSchemaOptions, Box<dyn std::error::Error>> {
+        let schema_override = self
+            .schema_override
+            .as_ref()
+            .map(|source| -> Result<Value, Box<dyn std::error::Error>> {
+                Ok(serde_json::from_str(&source.load()?)?)
+            })
+            .transpose()?;
+
+        let additional_schemas = self
+            .additional_schemas
+            .iter()
+            .map(|source| -> Result<Value, Box<dyn std::error::Error>> {
+                Ok(serde_json::from_str(&source.load()?)?)
+            })
+            .collect::<Result<Vec<Value>, Box<dyn std::error::Error>>>()?;
 
-pub fn validate_as_action(doc: &Value) -> ValidationState {
-    validate_with_schema(
-        doc,
+        Ok(LoadedSchemaOptions {
+            schema_override,
+            additional_schemas,
+        })
+    }
+}
+
+/// The parsed result of [`SchemaOptions::load`]: schema documents read from
+/// disk/network up front, ready to be reused across every file validated in
+/// this run without repeating the I/O.
+#[derive(Debug, Clone, Default)]
+pub struct LoadedSchemaOptions {
+    schema_override: Option<Value>,
+    additional_schemas: Vec<Value>,
+}
+
+pub fn validate_as_action(
+    doc: &Value,
+    schemas: &LoadedSchemaOptions,
+) -> Result<ValidationState, Box<dyn std::error::Error>> {
+    let schema = schema_or_bundled(
+        schemas,
         include_bytes!("schemastore/src/schemas/json/github-action.json"),
-    )
+    )?;
+
+    validate_with_schema(doc, &schema, schemas)
 }
 
-pub fn validate_as_workflow(doc: &Value) -> ValidationState {
-    validate_with_schema(
-        doc,
+pub fn validate_as_workflow(
+    doc: &Value,
+    schemas: &LoadedSchemaOptions,
+) -> Result<ValidationState, Box<dyn std::error::Error>> {
+    let schema = schema_or_bundled(
+        schemas,
         include_bytes!("schemastore/src/schemas/json/github-workflow.json"),
-    )
+    )?;
+
+    validate_with_schema(doc, &schema, schemas)
 }
 
-fn validate_with_schema(doc: &Value, schema: &[u8]) -> ValidationState {
-    let schema_json: serde_json::Value =
-        serde_json::from_str(String::from_utf8_lossy(schema).as_ref()).unwrap();
+fn schema_or_bundled(
+    schemas: &LoadedSchemaOptions,
+    bundled: &[u8],
+) -> Result<Value, Box<dyn std::error::Error>> {
+    match &schemas.schema_override {
+        Some(schema) => Ok(schema.clone()),
+        None => Ok(serde_json::from_slice(bundled)?),
+    }
+}
 
+fn validate_with_schema(
+    doc: &Value,
+    schema: &Value,
+    schemas: &LoadedSchemaOptions,
+) -> Result<ValidationState, Box<dyn std::error::Error>> {
     let mut scope = valico::json_schema::Scope::new();
-    let validator = scope.compile_and_return(schema_json, false).unwrap();
+
+    for additional in &schemas.additional_schemas {
+        scope.compile(additional.clone(), false)?;
+    }
+
+    let validator = scope.compile_and_return(schema.clone(), false)?;
 
     let state = validator.validate(doc);
 
@@ -30,5 +130,5 @@ fn validate_with_schema(doc: &Value, schema: &[u8]) -> ValidationState {
         error(&format!("Validation failed: {state:#?}"));
     }
 
-    state
+    Ok(ValidationState::from(state))
 }
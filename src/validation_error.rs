@@ -11,6 +11,10 @@ pub struct ValidationErrorMetadata {
     pub path: String,
     pub title: String,
     pub detail: Option<String>,
+    /// The line/column the error's `path` resolves to in the original YAML
+    /// source. Populated after schema validation by resolving `path` against
+    /// a location-preserving re-parse of the source; `None` until then.
+    pub location: Option<ParseErrorLocation>,
 }
 
 impl ValidationErrorMetadata {
@@ -20,6 +24,7 @@ impl ValidationErrorMetadata {
             path: err.get_path().into(),
             title: err.get_title().into(),
             detail: err.get_detail().map(|s| s.into()),
+            location: None,
         }
     }
 }
@@ -31,11 +36,12 @@ impl From<&BoxedValicoError> for ValidationErrorMetadata {
             path: err.get_path().into(),
             title: err.get_title().into(),
             detail: err.get_detail().map(|s| s.into()),
+            location: None,
         }
     }
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct ParseErrorLocation {
     pub index: usize,
     pub line: usize,
@@ -166,6 +172,18 @@ pub enum ValidationError {
     NoFilesMatchingGlobError {
         meta: ValidationErrorMetadata,
     },
+    InvalidCronError {
+        meta: ValidationErrorMetadata,
+    },
+    InvalidRunnerLabelError {
+        meta: ValidationErrorMetadata,
+    },
+    InvalidExpressionError {
+        meta: ValidationErrorMetadata,
+    },
+    SchemaLoadError {
+        meta: ValidationErrorMetadata,
+    },
 
     // Other Errors
     ParseError {
@@ -296,3 +314,109 @@ impl From<serde_yaml::Error> for ValidationError {
         ValidationError::ParseError { meta: err.into() }
     }
 }
+
+/// A flattened, variant-agnostic view of the fields common to every
+/// `ValidationError`, used by output formats (e.g. SARIF) that don't care
+/// which schema constraint actually failed.
+pub struct ValidationErrorSummary<'a> {
+    pub code: &'a str,
+    pub path: Option<&'a str>,
+    pub title: &'a str,
+    pub detail: Option<&'a str>,
+    pub location: Option<&'a ParseErrorLocation>,
+}
+
+impl ValidationError {
+    pub fn summary(&self) -> ValidationErrorSummary {
+        match self {
+            ValidationError::ParseError { meta } => ValidationErrorSummary {
+                code: &meta.code,
+                path: None,
+                title: &meta.title,
+                detail: Some(meta.detail.as_str()),
+                location: meta.location.as_ref(),
+            },
+            ValidationError::WrongTypeSchemaError { meta }
+            | ValidationError::MultipleOfSchemaError { meta }
+            | ValidationError::MaximumSchemaError { meta }
+            | ValidationError::MinimumSchemaError { meta }
+            | ValidationError::MaxLengthSchemaError { meta }
+            | ValidationError::MinLengthSchemaError { meta }
+            | ValidationError::PatternSchemaError { meta }
+            | ValidationError::MaxItemsSchemaError { meta }
+            | ValidationError::MinItemsSchemaError { meta }
+            | ValidationError::UniqueItemsSchemaError { meta }
+            | ValidationError::ItemsSchemaError { meta }
+            | ValidationError::MaxPropertiesSchemaError { meta }
+            | ValidationError::MinPropertiesSchemaError { meta }
+            | ValidationError::RequiredSchemaError { meta }
+            | ValidationError::PropertiesSchemaError { meta }
+            | ValidationError::EnumSchemaError { meta }
+            | ValidationError::ConstSchemaError { meta }
+            | ValidationError::ContainsSchemaError { meta }
+            | ValidationError::ContainsMinMaxSchemaError { meta }
+            | ValidationError::NotSchemaError { meta }
+            | ValidationError::DivergentDefaultsSchemaError { meta }
+            | ValidationError::FormatSchemaError { meta }
+            | ValidationError::UnevaluatedSchemaError { meta }
+            | ValidationError::UnknownSchemaError { meta }
+            | ValidationError::UnresolvedJobError { meta }
+            | ValidationError::InvalidGlobError { meta }
+            | ValidationError::NoFilesMatchingGlobError { meta }
+            | ValidationError::InvalidCronError { meta }
+            | ValidationError::InvalidRunnerLabelError { meta }
+            | ValidationError::InvalidExpressionError { meta }
+            | ValidationError::SchemaLoadError { meta }
+            | ValidationError::AnyOfSchemaError { meta, .. }
+            | ValidationError::OneOfSchemaError { meta, .. } => ValidationErrorSummary {
+                code: &meta.code,
+                path: Some(&meta.path),
+                title: &meta.title,
+                detail: meta.detail.as_deref(),
+                location: meta.location.as_ref(),
+            },
+        }
+    }
+
+    /// The mutable `ValidationErrorMetadata` shared by every variant except
+    /// `ParseError` (which carries `ParseErrorMetadata`, and already has its
+    /// own location attached at parse time).
+    pub fn metadata_mut(&mut self) -> Option<&mut ValidationErrorMetadata> {
+        match self {
+            ValidationError::ParseError { .. } => None,
+            ValidationError::WrongTypeSchemaError { meta }
+            | ValidationError::MultipleOfSchemaError { meta }
+            | ValidationError::MaximumSchemaError { meta }
+            | ValidationError::MinimumSchemaError { meta }
+            | ValidationError::MaxLengthSchemaError { meta }
+            | ValidationError::MinLengthSchemaError { meta }
+            | ValidationError::PatternSchemaError { meta }
+            | ValidationError::MaxItemsSchemaError { meta }
+            | ValidationError::MinItemsSchemaError { meta }
+            | ValidationError::UniqueItemsSchemaError { meta }
+            | ValidationError::ItemsSchemaError { meta }
+            | ValidationError::MaxPropertiesSchemaError { meta }
+            | ValidationError::MinPropertiesSchemaError { meta }
+            | ValidationError::RequiredSchemaError { meta }
+            | ValidationError::PropertiesSchemaError { meta }
+            | ValidationError::EnumSchemaError { meta }
+            | ValidationError::ConstSchemaError { meta }
+            | ValidationError::ContainsSchemaError { meta }
+            | ValidationError::ContainsMinMaxSchemaError { meta }
+            | ValidationError::NotSchemaError { meta }
+            | ValidationError::DivergentDefaultsSchemaError { meta }
+            | ValidationError::FormatSchemaError { meta }
+            | ValidationError::UnevaluatedSchemaError { meta }
+            | ValidationError::UnknownSchemaError { meta }
+            | ValidationError::UnresolvedJobError { meta }
+            | ValidationError::InvalidGlobError { meta }
+            | ValidationError::NoFilesMatchingGlobError { meta }
+            | ValidationError::InvalidCronError { meta }
+            | ValidationError::InvalidRunnerLabelError { meta }
+            | ValidationError::InvalidExpressionError { meta }
+            | ValidationError::SchemaLoadError { meta }
+            | ValidationError::AnyOfSchemaError { meta, .. }
+            | ValidationError::OneOfSchemaError { meta, .. } => Some(meta),
+        }
+    }
+}
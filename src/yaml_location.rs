@@ -0,0 +1,242 @@
+use std::collections::HashMap;
+
+use yaml_rust::parser::{Event, MarkedEventReceiver, Parser};
+use yaml_rust::scanner::Marker;
+
+use crate::validation_error::ParseErrorLocation;
+
+impl From<Marker> for ParseErrorLocation {
+    fn from(marker: Marker) -> Self {
+        ParseErrorLocation {
+            index: marker.index(),
+            line: marker.line(),
+            // `yaml_rust::scanner::Marker::col()` is 0-indexed, whereas
+            // `serde_yaml::Location::column()` (the other source of
+            // `ParseErrorLocation`, used for plain parse errors) is
+            // 1-indexed. Normalize to 1-indexed here so both paths agree,
+            // since that's also what SARIF's `startColumn` requires.
+            column: marker.col() + 1,
+        }
+    }
+}
+
+/// A YAML document re-parsed purely for the source positions of its mapping
+/// values, sequence items and scalars, mirroring the shape a JSON pointer
+/// walks. Mapping keys are kept by name only: a path ending at a key
+/// resolves to the position of that key's value, not of the key itself.
+#[derive(Clone)]
+enum LocationNode {
+    Scalar(ParseErrorLocation),
+    Mapping(ParseErrorLocation, HashMap<String, LocationNode>),
+    Sequence(ParseErrorLocation, Vec<LocationNode>),
+}
+
+impl LocationNode {
+    fn location(&self) -> &ParseErrorLocation {
+        match self {
+            LocationNode::Scalar(location) => location,
+            LocationNode::Mapping(location, _) => location,
+            LocationNode::Sequence(location, _) => location,
+        }
+    }
+
+    fn child(&self, segment: &str) -> Option<&LocationNode> {
+        match self {
+            LocationNode::Mapping(_, children) => children.get(segment),
+            LocationNode::Sequence(_, children) => {
+                children.get(segment.parse::<usize>().ok()?)
+            }
+            LocationNode::Scalar(_) => None,
+        }
+    }
+}
+
+enum Frame {
+    Mapping {
+        location: ParseErrorLocation,
+        children: HashMap<String, LocationNode>,
+        pending_key: Option<String>,
+        anchor_id: usize,
+    },
+    Sequence {
+        location: ParseErrorLocation,
+        children: Vec<LocationNode>,
+        anchor_id: usize,
+    },
+}
+
+#[derive(Default)]
+struct LocationTreeBuilder {
+    stack: Vec<Frame>,
+    root: Option<LocationNode>,
+    /// Nodes keyed by YAML anchor id, so `*alias` references (`Event::Alias`)
+    /// can resolve to the location of the node they point at instead of
+    /// being silently dropped.
+    anchors: HashMap<usize, LocationNode>,
+}
+
+impl LocationTreeBuilder {
+    fn record_value(&mut self, node: LocationNode) {
+        match self.stack.last_mut() {
+            Some(Frame::Mapping {
+                children,
+                pending_key,
+                ..
+            }) => {
+                if let Some(key) = pending_key.take() {
+                    children.insert(key, node);
+                }
+            }
+            Some(Frame::Sequence { children, .. }) => children.push(node),
+            None => self.root = Some(node),
+        }
+    }
+
+    fn record_anchor(&mut self, anchor_id: usize, node: &LocationNode) {
+        if anchor_id != 0 {
+            self.anchors.insert(anchor_id, node.clone());
+        }
+    }
+}
+
+impl MarkedEventReceiver for LocationTreeBuilder {
+    fn on_event(&mut self, event: Event, marker: Marker) {
+        match event {
+            Event::Scalar(value, _, anchor_id, _) => match self.stack.last_mut() {
+                Some(Frame::Mapping { pending_key, .. }) if pending_key.is_none() => {
+                    *pending_key = Some(value);
+                }
+                _ => {
+                    let node = LocationNode::Scalar(marker.into());
+                    self.record_anchor(anchor_id, &node);
+                    self.record_value(node);
+                }
+            },
+            Event::MappingStart(anchor_id, ..) => self.stack.push(Frame::Mapping {
+                location: marker.into(),
+                children: HashMap::new(),
+                pending_key: None,
+                anchor_id,
+            }),
+            Event::MappingEnd => {
+                if let Some(Frame::Mapping {
+                    location,
+                    children,
+                    anchor_id,
+                    ..
+                }) = self.stack.pop()
+                {
+                    let node = LocationNode::Mapping(location, children);
+                    self.record_anchor(anchor_id, &node);
+                    self.record_value(node);
+                }
+            }
+            Event::SequenceStart(anchor_id, ..) => self.stack.push(Frame::Sequence {
+                location: marker.into(),
+                children: Vec::new(),
+                anchor_id,
+            }),
+            Event::SequenceEnd => {
+                if let Some(Frame::Sequence {
+                    location,
+                    children,
+                    anchor_id,
+                }) = self.stack.pop()
+                {
+                    let node = LocationNode::Sequence(location, children);
+                    self.record_anchor(anchor_id, &node);
+                    self.record_value(node);
+                }
+            }
+            Event::Alias(anchor_id) => match self.anchors.get(&anchor_id).cloned() {
+                Some(node) => self.record_value(node),
+                // Unknown/forward alias: at least stop the pending key from
+                // leaking into the next sibling's value.
+                None => {
+                    if let Some(Frame::Mapping { pending_key, .. }) = self.stack.last_mut() {
+                        *pending_key = None;
+                    }
+                }
+            },
+            _ => {}
+        }
+    }
+}
+
+/// An index from JSON pointer path to the source location it resolves to,
+/// built by re-parsing the YAML source with position-tracking events.
+pub struct LocationIndex {
+    root: Option<LocationNode>,
+}
+
+impl LocationIndex {
+    pub fn build(src: &str) -> Self {
+        let mut parser = Parser::new(src.chars());
+        let mut builder = LocationTreeBuilder::default();
+        let _ = parser.load(&mut builder, false);
+
+        LocationIndex { root: builder.root }
+    }
+
+    /// Resolves a JSON pointer (e.g. `/jobs/build/steps/0/uses`) to a source
+    /// location, walking the pointer segment-by-segment. Falls back to the
+    /// nearest resolvable ancestor when a segment can't be found, e.g. when
+    /// the error points at a required property that's absent from the YAML.
+    pub fn resolve(&self, pointer: &str) -> Option<ParseErrorLocation> {
+        let mut node = self.root.as_ref()?;
+
+        for segment in pointer.split('/').filter(|segment| !segment.is_empty()) {
+            match node.child(segment) {
+                Some(next) => node = next,
+                None => break,
+            }
+        }
+
+        Some(node.location().clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_nested_mapping_and_sequence_segments() {
+        let index = LocationIndex::build("jobs:\n  build:\n    steps:\n      - uses: actions/checkout@v4\n");
+
+        let location = index.resolve("/jobs/build/steps/0/uses").unwrap();
+
+        assert_eq!(location.line, 4);
+        // 1-indexed, matching `serde_yaml::Location::column()`.
+        assert_eq!(location.column, 9);
+    }
+
+    #[test]
+    fn falls_back_to_nearest_ancestor_for_missing_segment() {
+        let index = LocationIndex::build("jobs:\n  build:\n    runs-on: ubuntu-latest\n");
+
+        let location = index.resolve("/jobs/build/missing").unwrap();
+
+        assert_eq!(location, index.resolve("/jobs/build").unwrap());
+    }
+
+    #[test]
+    fn resolves_alias_to_its_anchored_node_location() {
+        let index = LocationIndex::build("defaults: &defaults\n  shell: bash\njobs:\n  build:\n    defaults: *defaults\n");
+
+        let anchor = index.resolve("/defaults").unwrap();
+        let alias = index.resolve("/jobs/build/defaults").unwrap();
+
+        assert_eq!(anchor, alias);
+    }
+
+    #[test]
+    fn alias_does_not_corrupt_later_mapping_keys() {
+        let index =
+            LocationIndex::build("x: &x foo\njobs:\n  build:\n    a: *x\n    b: bar\n");
+
+        let b = index.resolve("/jobs/build/b").unwrap();
+
+        assert_eq!(b.line, 5);
+    }
+}